@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use serde_json::Value;
+
+/// Errors returned by [`crate::Client`].
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The request could not be sent (connection refused, DNS failure, TLS error, ...).
+    #[error("network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    /// The request timed out before a response was received.
+    #[error("request timed out")]
+    Timeout,
+
+    /// The response body could not be read or parsed as the expected JSON shape.
+    /// Also returned when a request body cannot be serialized or gzip-encoded.
+    #[error("failed to decode response: {message} (body: {body})")]
+    Decode {
+        message: String,
+        /// A snippet of the raw response body, truncated for readability.
+        body: String,
+    },
+
+    /// The server responded with 429 Too Many Requests.
+    #[error("rate limited{}", retry_after.map(|d| format!(", retry after {:?}", d)).unwrap_or_default())]
+    RateLimited { retry_after: Option<Duration> },
+
+    /// The server responded with a non-retryable 4xx/5xx status, or with a
+    /// retryable 500/502/503/504 on the last allowed attempt.
+    #[error(
+        "api error: {message} (status={status}){}{}",
+        code.as_deref().map(|c| format!(" (code={c})")).unwrap_or_default(),
+        request_id.as_deref().map(|id| format!(" (request_id={id})")).unwrap_or_default()
+    )]
+    Api {
+        status: u16,
+        code: Option<String>,
+        message: String,
+        details: Option<Value>,
+        request_id: Option<String>,
+    },
+
+    /// The retry loop ended without producing a response or an error. When
+    /// retries run out, [`crate::Client`] returns the last attempt's own error
+    /// (`RateLimited`, `Api`, `Timeout` or `Network`) instead of this variant.
+    #[error("max retries exceeded")]
+    MaxRetriesExceeded,
+}
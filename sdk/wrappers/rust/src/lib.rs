@@ -1,13 +1,28 @@
 use std::collections::HashMap;
 use std::error::Error as StdError;
-use std::fmt;
+use std::io::Write;
 use std::time::Duration;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+mod error;
+mod pagination;
+mod query;
+mod retry;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use error::Error;
+pub use query::QueryParams;
+
+use retry::parse_retry_after;
+
 const VERSION: &str = "0.1.0";
 const DEFAULT_TIMEOUT_SECS: u64 = 15;
 const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_BACKOFF_BASE_MS: u64 = 1000;
+const DEFAULT_BACKOFF_MAX_MS: u64 = 8000;
 
 /// SDK configuration options
 #[derive(Debug, Clone)]
@@ -22,6 +37,15 @@ pub struct ClientOptions {
     pub timeout_secs: Option<u64>,
     /// Maximum number of retry attempts (default: 3)
     pub max_retries: Option<u32>,
+    /// Base backoff duration in milliseconds, doubled per attempt (default: 1000)
+    pub backoff_base_ms: Option<u64>,
+    /// Backoff cap in milliseconds, before jitter is applied (default: 8000)
+    pub backoff_max_ms: Option<u64>,
+    /// Apply full-jitter randomization to computed backoffs (default: true)
+    pub jitter: bool,
+    /// Gzip-encode request bodies at or above this size, and transparently
+    /// decompress gzip/deflate responses (disabled by default)
+    pub compression_min_bytes: Option<usize>,
     /// Custom user agent (optional)
     pub user_agent: Option<String>,
     /// Additional custom headers (optional)
@@ -38,6 +62,10 @@ impl ClientOptions {
             bearer_token: None,
             timeout_secs: None,
             max_retries: None,
+            backoff_base_ms: None,
+            backoff_max_ms: None,
+            jitter: true,
+            compression_min_bytes: None,
             user_agent: None,
             custom_headers: None,
             debug: false,
@@ -64,41 +92,29 @@ impl ClientOptions {
         self
     }
 
-    pub fn with_debug(mut self, debug: bool) -> Self {
-        self.debug = debug;
+    pub fn with_backoff(mut self, base_ms: u64, max_ms: u64) -> Self {
+        self.backoff_base_ms = Some(base_ms);
+        self.backoff_max_ms = Some(max_ms);
         self
     }
-}
 
-/// API error with structured information
-#[derive(Debug, Clone)]
-pub struct APIError {
-    pub message: String,
-    pub status: Option<u16>,
-    pub code: Option<String>,
-    pub details: Option<Value>,
-    pub request_id: Option<String>,
-}
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
 
-impl fmt::Display for APIError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut parts = vec![self.message.clone()];
-        
-        if let Some(status) = self.status {
-            parts.push(format!("(status={})", status));
-        }
-        if let Some(code) = &self.code {
-            parts.push(format!("(code={})", code));
-        }
-        if let Some(request_id) = &self.request_id {
-            parts.push(format!("(request_id={})", request_id));
-        }
-        
-        write!(f, "{}", parts.join(" "))
+    /// Gzip-encode request bodies that are at least `min_body_bytes` long, and
+    /// negotiate gzip/deflate response compression.
+    pub fn with_compression(mut self, min_body_bytes: usize) -> Self {
+        self.compression_min_bytes = Some(min_body_bytes);
+        self
     }
-}
 
-impl StdError for APIError {}
+    pub fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+}
 
 /// Cursor-based paginated response
 #[derive(Debug, Deserialize, Serialize)]
@@ -128,6 +144,10 @@ pub struct Client {
     base_url: String,
     http_client: reqwest::Client,
     max_retries: u32,
+    backoff_base_ms: u64,
+    backoff_max_ms: u64,
+    jitter: bool,
+    compression_min_bytes: Option<usize>,
     debug: bool,
 }
 
@@ -136,6 +156,8 @@ impl Client {
     pub fn new(options: ClientOptions) -> Result<Self, Box<dyn StdError>> {
         let timeout = Duration::from_secs(options.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
         let max_retries = options.max_retries.unwrap_or(DEFAULT_MAX_RETRIES);
+        let backoff_base_ms = options.backoff_base_ms.unwrap_or(DEFAULT_BACKOFF_BASE_MS);
+        let backoff_max_ms = options.backoff_max_ms.unwrap_or(DEFAULT_BACKOFF_MAX_MS);
         let user_agent = options.user_agent.unwrap_or_else(|| format!("yourapi-rust-sdk/{}", VERSION));
 
         // Build default headers
@@ -176,15 +198,25 @@ impl Client {
             }
         }
 
+        // `Accept-Encoding` and response decompression are handled by reqwest
+        // itself once these builder flags are set, rather than by a manually
+        // inserted header (which wouldn't actually decompress anything).
+        let compression_enabled = options.compression_min_bytes.is_some();
         let http_client = reqwest::Client::builder()
             .timeout(timeout)
             .default_headers(headers)
+            .gzip(compression_enabled)
+            .deflate(compression_enabled)
             .build()?;
 
         Ok(Self {
             base_url: options.base_url.trim_end_matches('/').to_string(),
             http_client,
             max_retries,
+            backoff_base_ms,
+            backoff_max_ms,
+            jitter: options.jitter,
+            compression_min_bytes: options.compression_min_bytes,
             debug: options.debug,
         })
     }
@@ -195,17 +227,65 @@ impl Client {
         }
     }
 
-    fn calculate_backoff(&self, attempt: u32, retry_after: Option<u64>) -> Duration {
-        if let Some(seconds) = retry_after {
-            return Duration::from_secs(seconds);
+    /// Full-jitter exponential backoff: `sleep(uniform(0, min(max, base * 2^attempt)))`.
+    /// An explicit `Retry-After` always takes precedence over the computed jitter.
+    fn calculate_backoff(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(delay) = retry_after {
+            return delay;
         }
-        
-        // Exponential backoff: 2^attempt, capped at 8 seconds
-        let backoff_secs = 2u64.pow(attempt).min(8);
-        Duration::from_secs(backoff_secs)
+
+        let cap_ms = self
+            .backoff_base_ms
+            .saturating_mul(1u64 << attempt.min(63))
+            .min(self.backoff_max_ms);
+
+        if !self.jitter {
+            return Duration::from_millis(cap_ms);
+        }
+
+        let jittered_ms = rand::thread_rng().gen_range(0..=cap_ms);
+        Duration::from_millis(jittered_ms)
+    }
+
+    /// Serialize `body` as JSON and attach it to `request`, gzip-encoding it
+    /// first when compression is enabled and the body meets the configured
+    /// size threshold.
+    fn attach_body(
+        &self,
+        request: reqwest::RequestBuilder,
+        body: &Value,
+    ) -> Result<reqwest::RequestBuilder, Error> {
+        let bytes = serde_json::to_vec(body).map_err(|e| Error::Decode {
+            message: format!("failed to encode request body: {}", e),
+            body: String::new(),
+        })?;
+
+        let should_compress = self
+            .compression_min_bytes
+            .is_some_and(|min_bytes| bytes.len() >= min_bytes);
+        if !should_compress {
+            return Ok(request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(bytes));
+        }
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&bytes).map_err(|e| Error::Decode {
+            message: format!("failed to gzip request body: {}", e),
+            body: String::new(),
+        })?;
+        let compressed = encoder.finish().map_err(|e| Error::Decode {
+            message: format!("failed to gzip request body: {}", e),
+            body: String::new(),
+        })?;
+
+        Ok(request
+            .header(reqwest::header::CONTENT_ENCODING, "gzip")
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(compressed))
     }
 
-    async fn parse_error(&self, response: reqwest::Response) -> APIError {
+    async fn parse_error(&self, response: reqwest::Response) -> Error {
         let status = response.status().as_u16();
         let request_id = response
             .headers()
@@ -226,18 +306,18 @@ impl Client {
                     .map(|s| s.to_string())
                     .or(request_id);
 
-                APIError {
-                    message,
-                    status: Some(status),
+                Error::Api {
+                    status,
                     code,
+                    message,
                     details,
                     request_id: req_id,
                 }
             }
-            Err(_) => APIError {
-                message: format!("Request failed with status {}", status),
-                status: Some(status),
+            Err(_) => Error::Api {
+                status,
                 code: None,
+                message: format!("Request failed with status {}", status),
                 details: None,
                 request_id,
             },
@@ -250,7 +330,7 @@ impl Client {
         path: &str,
         body: Option<Value>,
         headers: Option<HashMap<String, String>>,
-    ) -> Result<Option<Value>, APIError> {
+    ) -> Result<Option<Value>, Error> {
         let url = format!("{}{}", self.base_url, path);
         
         for attempt in 0..=self.max_retries {
@@ -265,7 +345,7 @@ impl Client {
             let mut request = self.http_client.request(method.clone(), &url);
 
             if let Some(ref body_data) = body {
-                request = request.json(body_data);
+                request = self.attach_body(request, body_data)?;
             }
 
             if let Some(ref extra_headers) = headers {
@@ -284,67 +364,70 @@ impl Client {
                         if status.as_u16() == 204 {
                             return Ok(None);
                         }
-                        match response.json::<Value>().await {
+                        let text = response.text().await.map_err(|e| Error::Decode {
+                            message: format!("failed to read response body: {}", e),
+                            body: String::new(),
+                        })?;
+                        match serde_json::from_str::<Value>(&text) {
                             Ok(data) => return Ok(Some(data)),
                             Err(e) => {
-                                return Err(APIError {
-                                    message: format!("Failed to parse response: {}", e),
-                                    status: Some(status.as_u16()),
-                                    code: Some("PARSE_ERROR".to_string()),
-                                    details: None,
-                                    request_id: None,
+                                return Err(Error::Decode {
+                                    message: e.to_string(),
+                                    body: body_snippet(&text),
                                 });
                             }
                         }
                     }
 
+                    let retry_after = response
+                        .headers()
+                        .get("retry-after")
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(parse_retry_after);
+
                     // Check for retryable errors
-                    let retryable_statuses = vec![429, 500, 502, 503, 504];
+                    let retryable_statuses = [429, 500, 502, 503, 504];
                     if retryable_statuses.contains(&status.as_u16()) && attempt < self.max_retries {
-                        let retry_after = response
-                            .headers()
-                            .get("retry-after")
-                            .and_then(|v| v.to_str().ok())
-                            .and_then(|s| s.parse::<u64>().ok());
-
                         let backoff = self.calculate_backoff(attempt, retry_after);
                         self.log_debug(&format!("Retrying after {:?}", backoff));
                         tokio::time::sleep(backoff).await;
                         continue;
                     }
 
+                    if status.as_u16() == 429 {
+                        return Err(Error::RateLimited { retry_after });
+                    }
+
                     // Non-retryable error
                     return Err(self.parse_error(response).await);
                 }
                 Err(e) => {
+                    if e.is_timeout() {
+                        if attempt < self.max_retries {
+                            let backoff = self.calculate_backoff(attempt, None);
+                            self.log_debug(&format!("Timed out, retrying after {:?}", backoff));
+                            tokio::time::sleep(backoff).await;
+                            continue;
+                        }
+                        return Err(Error::Timeout);
+                    }
+
                     if attempt < self.max_retries {
                         let backoff = self.calculate_backoff(attempt, None);
                         self.log_debug(&format!("Request error, retrying after {:?}: {}", backoff, e));
                         tokio::time::sleep(backoff).await;
                         continue;
                     }
-                    return Err(APIError {
-                        message: format!("Request failed: {}", e),
-                        status: None,
-                        code: Some("REQUEST_ERROR".to_string()),
-                        details: None,
-                        request_id: None,
-                    });
+                    return Err(Error::Network(e));
                 }
             }
         }
 
-        Err(APIError {
-            message: "Max retries exceeded".to_string(),
-            status: None,
-            code: Some("MAX_RETRIES_EXCEEDED".to_string()),
-            details: None,
-            request_id: None,
-        })
+        Err(Error::MaxRetriesExceeded)
     }
 
     /// Make a GET request
-    pub async fn get(&self, path: &str) -> Result<Option<Value>, APIError> {
+    pub async fn get(&self, path: &str) -> Result<Option<Value>, Error> {
         self.do_request(reqwest::Method::GET, path, None, None).await
     }
 
@@ -354,28 +437,28 @@ impl Client {
         path: &str,
         data: Value,
         idempotency_key: Option<String>,
-    ) -> Result<Option<Value>, APIError> {
+    ) -> Result<Option<Value>, Error> {
         let mut headers = HashMap::new();
         if let Some(key) = idempotency_key {
             headers.insert("Idempotency-Key".to_string(), key);
         }
-        
+
         let headers_opt = if headers.is_empty() { None } else { Some(headers) };
         self.do_request(reqwest::Method::POST, path, Some(data), headers_opt).await
     }
 
     /// Make a PATCH request
-    pub async fn patch(&self, path: &str, data: Value) -> Result<Option<Value>, APIError> {
+    pub async fn patch(&self, path: &str, data: Value) -> Result<Option<Value>, Error> {
         self.do_request(reqwest::Method::PATCH, path, Some(data), None).await
     }
 
     /// Make a PUT request
-    pub async fn put(&self, path: &str, data: Value) -> Result<Option<Value>, APIError> {
+    pub async fn put(&self, path: &str, data: Value) -> Result<Option<Value>, Error> {
         self.do_request(reqwest::Method::PUT, path, Some(data), None).await
     }
 
     /// Make a DELETE request
-    pub async fn delete(&self, path: &str) -> Result<Option<Value>, APIError> {
+    pub async fn delete(&self, path: &str) -> Result<Option<Value>, Error> {
         self.do_request(reqwest::Method::DELETE, path, None, None).await
     }
 
@@ -383,31 +466,23 @@ impl Client {
     pub async fn paginate_cursor<T: for<'de> Deserialize<'de>>(
         &self,
         path: &str,
-    ) -> Result<Vec<T>, APIError> {
+    ) -> Result<Vec<T>, Error> {
         let mut all_items = Vec::new();
         let mut cursor: Option<String> = None;
         let mut has_more = true;
 
         while has_more {
-            let current_path = if let Some(ref c) = cursor {
-                if path.contains('?') {
-                    format!("{}&cursor={}", path, c)
-                } else {
-                    format!("{}?cursor={}", path, c)
-                }
-            } else {
-                path.to_string()
+            let params = match &cursor {
+                Some(c) => QueryParams::new().push("cursor", c.clone()),
+                None => QueryParams::new(),
             };
 
-            match self.get(&current_path).await? {
+            match self.get_with_query(path, &params).await? {
                 Some(data) => {
-                    let response: CursorPaginatedResponse<T> = serde_json::from_value(data)
-                        .map_err(|e| APIError {
+                    let response: CursorPaginatedResponse<T> =
+                        serde_json::from_value(data.clone()).map_err(|e| Error::Decode {
                             message: format!("Failed to parse paginated response: {}", e),
-                            status: None,
-                            code: Some("PARSE_ERROR".to_string()),
-                            details: None,
-                            request_id: None,
+                            body: body_snippet(&data.to_string()),
                         })?;
 
                     all_items.extend(response.items);
@@ -422,3 +497,57 @@ impl Client {
     }
 }
 
+/// Truncate a response body for inclusion in a [`Error::Decode`] message.
+pub(crate) fn body_snippet(body: &str) -> String {
+    const MAX_CHARS: usize = 200;
+    if body.chars().count() <= MAX_CHARS {
+        body.to_string()
+    } else {
+        format!("{}...", body.chars().take(MAX_CHARS).collect::<String>())
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod compression_tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn gzip_compresses_request_body_and_decompresses_gzip_response() {
+        let server = MockServer::start().await;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder
+            .write_all(br#"{"id":"ch_1","status":"succeeded"}"#)
+            .unwrap();
+        let gzip_response_body = encoder.finish().unwrap();
+
+        // Requiring a gzip `Content-Encoding` on the incoming request proves
+        // the client actually compressed the body, not just declared support for it.
+        Mock::given(method("POST"))
+            .and(path("/v1/charges"))
+            .and(header("content-encoding", "gzip"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-encoding", "gzip")
+                    .set_body_bytes(gzip_response_body),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::new(ClientOptions::new(server.uri()).with_compression(1)).unwrap();
+
+        let payload = serde_json::json!({ "notes": "x".repeat(64) });
+        let response = client.post("/v1/charges", payload, None).await.unwrap();
+
+        assert_eq!(
+            response,
+            Some(serde_json::json!({ "id": "ch_1", "status": "succeeded" }))
+        );
+
+        server.verify().await;
+    }
+}
+
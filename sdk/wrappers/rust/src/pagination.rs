@@ -0,0 +1,121 @@
+use async_stream::try_stream;
+use futures::Stream;
+use serde::Deserialize;
+
+use crate::{Client, CursorPaginatedResponse, Error, PagePaginatedResponse, QueryParams};
+
+/// `perPage` used by [`Client::paginate_page`] / [`Client::stream_page`] when the
+/// caller doesn't specify one.
+const DEFAULT_PAGE_SIZE: u32 = 25;
+
+fn page_query(page: u32, per_page: u32) -> QueryParams {
+    QueryParams::new()
+        .push("page", page.to_string())
+        .push("perPage", per_page.to_string())
+}
+
+impl Client {
+    /// Paginate through page-numbered API results, merging every page into a single `Vec`.
+    ///
+    /// Issues `?page=N&perPage=…` requests until `page >= totalPages`. `per_page`
+    /// defaults to [`DEFAULT_PAGE_SIZE`] when `None`.
+    pub async fn paginate_page<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        per_page: Option<u32>,
+    ) -> Result<Vec<T>, Error> {
+        let per_page = per_page.unwrap_or(DEFAULT_PAGE_SIZE);
+        let mut all_items = Vec::new();
+        let mut page = 1;
+
+        while let Some(data) = self.get_with_query(path, &page_query(page, per_page)).await? {
+            let response: PagePaginatedResponse<T> =
+                serde_json::from_value(data.clone()).map_err(|e| Error::Decode {
+                    message: format!("Failed to parse paginated response: {}", e),
+                    body: crate::body_snippet(&data.to_string()),
+                })?;
+
+            let total_pages = response.total_pages;
+            all_items.extend(response.items);
+
+            if page >= total_pages {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(all_items)
+    }
+
+    /// Lazily stream cursor-paginated results, fetching the next page only once the
+    /// current one has been drained.
+    pub fn stream_cursor<'a, T: for<'de> Deserialize<'de> + 'a>(
+        &'a self,
+        path: &'a str,
+    ) -> impl Stream<Item = Result<T, Error>> + 'a {
+        try_stream! {
+            let mut cursor: Option<String> = None;
+            let mut has_more = true;
+
+            while has_more {
+                let params = match &cursor {
+                    Some(c) => QueryParams::new().push("cursor", c.clone()),
+                    None => QueryParams::new(),
+                };
+
+                let Some(data) = self.get_with_query(path, &params).await? else {
+                    break;
+                };
+
+                let response: CursorPaginatedResponse<T> =
+                    serde_json::from_value(data.clone()).map_err(|e| Error::Decode {
+                        message: format!("Failed to parse paginated response: {}", e),
+                        body: crate::body_snippet(&data.to_string()),
+                    })?;
+
+                cursor = response.next_cursor;
+                has_more = response.has_more && cursor.is_some();
+
+                for item in response.items {
+                    yield item;
+                }
+            }
+        }
+    }
+
+    /// Lazily stream page-numbered results, fetching the next page only once the
+    /// current one has been drained. `per_page` defaults to [`DEFAULT_PAGE_SIZE`]
+    /// when `None`.
+    pub fn stream_page<'a, T: for<'de> Deserialize<'de> + 'a>(
+        &'a self,
+        path: &'a str,
+        per_page: Option<u32>,
+    ) -> impl Stream<Item = Result<T, Error>> + 'a {
+        let per_page = per_page.unwrap_or(DEFAULT_PAGE_SIZE);
+        try_stream! {
+            let mut page = 1;
+
+            loop {
+                let Some(data) = self.get_with_query(path, &page_query(page, per_page)).await? else {
+                    break;
+                };
+
+                let response: PagePaginatedResponse<T> =
+                    serde_json::from_value(data.clone()).map_err(|e| Error::Decode {
+                        message: format!("Failed to parse paginated response: {}", e),
+                        body: crate::body_snippet(&data.to_string()),
+                    })?;
+
+                let total_pages = response.total_pages;
+                for item in response.items {
+                    yield item;
+                }
+
+                if page >= total_pages {
+                    break;
+                }
+                page += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use url::Url;
+
+use crate::{Client, Error};
+
+/// A small builder for percent-encoded query strings, so callers and
+/// internal paginators don't have to hand-roll `?`/`&` concatenation.
+#[derive(Debug, Clone, Default)]
+pub struct QueryParams {
+    pairs: Vec<(String, String)>,
+}
+
+impl QueryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a key/value pair. Values are percent-encoded when the params
+    /// are applied to a path, so raw `&`, `=`, and spaces round-trip safely.
+    pub fn push(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.pairs.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Merge these params into `path`, preserving any query string `path`
+    /// already has.
+    ///
+    /// A malformed `path` is a caller bug, not something this builder should
+    /// crash the process over, so it falls back to returning `path` unchanged
+    /// rather than panicking.
+    pub(crate) fn apply_to(&self, path: &str) -> String {
+        if self.pairs.is_empty() {
+            return path.to_string();
+        }
+
+        // `path` is relative (e.g. "/v1/items?foo=bar"); join it against a
+        // throwaway base purely so `url::Url` can manage query encoding, then
+        // strip the base back off.
+        let base = Url::parse("http://sdk.invalid").expect("static base URL is valid");
+        let Ok(mut url) = base.join(path) else {
+            return path.to_string();
+        };
+
+        {
+            let mut query = url.query_pairs_mut();
+            for (key, value) in &self.pairs {
+                query.append_pair(key, value);
+            }
+        }
+
+        format!(
+            "{}{}",
+            url.path(),
+            url.query().map(|q| format!("?{}", q)).unwrap_or_default()
+        )
+    }
+}
+
+impl Client {
+    /// Make a GET request with typed, percent-encoded query parameters.
+    pub async fn get_with_query(
+        &self,
+        path: &str,
+        params: &QueryParams,
+    ) -> Result<Option<serde_json::Value>, Error> {
+        self.get(&params.apply_to(path)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_pairs(path: &str) -> Vec<(String, String)> {
+        let base = Url::parse("http://sdk.invalid").unwrap();
+        base.join(path)
+            .unwrap()
+            .query_pairs()
+            .into_owned()
+            .collect()
+    }
+
+    #[test]
+    fn no_params_leaves_path_untouched() {
+        let params = QueryParams::new();
+        assert_eq!(params.apply_to("/v1/items?foo=bar"), "/v1/items?foo=bar");
+    }
+
+    #[test]
+    fn cursor_with_special_characters_round_trips() {
+        let cursor = "a&b=c d";
+        let params = QueryParams::new().push("cursor", cursor);
+        let applied = params.apply_to("/v1/items");
+
+        // The raw delimiter characters must not appear unescaped in the query string.
+        assert!(!applied.contains("a&b=c d"));
+        assert_eq!(decode_pairs(&applied), vec![("cursor".to_string(), cursor.to_string())]);
+    }
+
+    #[test]
+    fn preserves_existing_query_string() {
+        let params = QueryParams::new().push("cursor", "abc");
+        let applied = params.apply_to("/v1/items?foo=bar");
+
+        assert_eq!(
+            decode_pairs(&applied),
+            vec![
+                ("foo".to_string(), "bar".to_string()),
+                ("cursor".to_string(), "abc".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_path_falls_back_unchanged_instead_of_panicking() {
+        let params = QueryParams::new().push("cursor", "abc");
+        // An embedded "host" with an invalid IPv6 literal fails `Url::join`
+        // outright rather than being percent-encoded away.
+        let malformed = "//[not-an-ipv6]/path";
+
+        assert_eq!(params.apply_to(malformed), malformed);
+    }
+
+    #[test]
+    fn multiple_params_are_all_applied() {
+        let params = QueryParams::new().push("page", "2").push("perPage", "50");
+        let applied = params.apply_to("/v1/items");
+
+        assert_eq!(
+            decode_pairs(&applied),
+            vec![
+                ("page".to_string(), "2".to_string()),
+                ("perPage".to_string(), "50".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use std::time::{Duration, SystemTime};
+
+/// Parse a `Retry-After` header value per RFC 7231: either an integer number
+/// of delta-seconds (`"120"`) or an HTTP-date (`"Wed, 21 Oct 2015 07:28:00 GMT"`).
+///
+/// Dates in the past resolve to `Duration::ZERO` rather than `None`, since the
+/// server's intent ("don't retry yet") is clear even if the clock has moved on.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let when = httpdate::parse_http_date(value.trim()).ok()?;
+    Some(
+        when.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_delta_seconds() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn parses_delta_seconds_with_surrounding_whitespace() {
+        assert_eq!(parse_retry_after(" 5 "), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn parses_http_date_in_the_future() {
+        let when = SystemTime::now() + Duration::from_secs(3600);
+        let header = httpdate::fmt_http_date(when);
+
+        let parsed = parse_retry_after(&header).expect("valid HTTP-date");
+        // Allow a little slack for the time spent formatting/parsing above.
+        assert!(parsed.as_secs() >= 3590 && parsed.as_secs() <= 3600);
+    }
+
+    #[test]
+    fn past_http_date_clamps_to_zero() {
+        let when = SystemTime::now() - Duration::from_secs(3600);
+        let header = httpdate::fmt_http_date(when);
+
+        assert_eq!(parse_retry_after(&header), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert_eq!(parse_retry_after("not-a-retry-after"), None);
+    }
+}
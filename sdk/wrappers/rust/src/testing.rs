@@ -0,0 +1,249 @@
+//! In-crate mock testing harness, gated behind the `testing` feature.
+//!
+//! Spins up a local [`wiremock`] server and records the requests the SDK is
+//! expected to make against it, so integration tests can assert on headers,
+//! query parameters, bodies, and retry behavior without hitting a live API.
+
+use serde_json::Value;
+use wiremock::matchers::{any, body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::{Client, ClientOptions};
+
+/// Which paginated envelope shape, if any, an [`ExpectedRequest`]'s default
+/// response should take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PaginationKind {
+    None,
+    Cursor,
+    Page,
+}
+
+/// Describes a single request the SDK is expected to make, and the canned
+/// response the mock server should return for it.
+#[derive(Debug, Clone)]
+pub struct ExpectedRequest {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    headers: Vec<(String, String)>,
+    body: Option<Value>,
+    status: u16,
+    response_body: Option<Value>,
+    pagination: PaginationKind,
+    expect_times: u64,
+}
+
+impl ExpectedRequest {
+    /// Start describing an expectation for `method path`, e.g. `("POST", "/v1/charges")`.
+    pub fn new(method: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            method: method.into(),
+            path: path.into(),
+            query: Vec::new(),
+            headers: Vec::new(),
+            body: None,
+            status: 200,
+            response_body: None,
+            pagination: PaginationKind::None,
+            expect_times: 1,
+        }
+    }
+
+    /// Require the request to carry this query parameter.
+    pub fn with_query(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.push((key.into(), value.into()));
+        self
+    }
+
+    /// Require the request to carry this header, e.g. `Idempotency-Key` or
+    /// `Authorization`.
+    pub fn with_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((key.into(), value.into()));
+        self
+    }
+
+    /// Require the request body to match this JSON value exactly.
+    pub fn with_body(mut self, body: Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Set the canned response returned when this expectation matches.
+    pub fn respond_with(mut self, status: u16, body: Value) -> Self {
+        self.status = status;
+        self.response_body = Some(body);
+        self
+    }
+
+    /// Mark this endpoint as returning a cursor-paginated envelope
+    /// (`items`/`nextCursor`/`hasMore`) by default.
+    pub fn cursor_paginated(mut self) -> Self {
+        self.pagination = PaginationKind::Cursor;
+        self
+    }
+
+    /// Mark this endpoint as returning a page-paginated envelope
+    /// (`items`/`page`/`perPage`/`totalPages`/`totalItems`) by default.
+    pub fn page_paginated(mut self) -> Self {
+        self.pagination = PaginationKind::Page;
+        self
+    }
+
+    /// Expect this request to be made exactly `n` times (e.g. to assert retries replay it).
+    pub fn times(mut self, n: u64) -> Self {
+        self.expect_times = n;
+        self
+    }
+}
+
+/// A [`Client`] wired up to a local [`wiremock`] server for use in tests.
+pub struct MockClient {
+    server: MockServer,
+    client: Client,
+}
+
+impl MockClient {
+    /// Start a local mock server and build a [`Client`] pointed at it.
+    ///
+    /// A catch-all mock is mounted at the lowest priority so any request that
+    /// doesn't match a registered [`ExpectedRequest`] is still recorded and
+    /// fails [`MockClient::verify`], instead of silently getting wiremock's
+    /// default 404.
+    pub async fn start(configure: impl FnOnce(ClientOptions) -> ClientOptions) -> Self {
+        let server = MockServer::start().await;
+
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(501))
+            .expect(0)
+            .with_priority(255)
+            .named("unexpected request")
+            .mount(&server)
+            .await;
+
+        let options = configure(ClientOptions::new(server.uri()));
+        let client = Client::new(options).expect("valid mock ClientOptions");
+        Self { server, client }
+    }
+
+    /// The [`Client`] under test.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// The mock server's base URL.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// Register an expectation. Requests that don't match any registered
+    /// expectation cause the mock server to fail the assertion when later
+    /// verified (see [`MockClient::verify`]).
+    pub async fn expect(&self, expected: ExpectedRequest) {
+        let mut mock = Mock::given(method(expected.method.as_str())).and(path(expected.path.clone()));
+
+        for (key, value) in &expected.query {
+            mock = mock.and(query_param(key.clone(), value.clone()));
+        }
+        for (key, value) in &expected.headers {
+            mock = mock.and(header(key.as_str(), value.as_str()));
+        }
+        if let Some(body) = &expected.body {
+            mock = mock.and(body_json(body.clone()));
+        }
+
+        let response_body = expected
+            .response_body
+            .clone()
+            .unwrap_or_else(|| envelope_for(expected.pagination));
+        let template = ResponseTemplate::new(expected.status).set_body_json(response_body);
+
+        mock.respond_with(template)
+            .expect(expected.expect_times)
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Assert every registered expectation was satisfied the expected number of times.
+    pub async fn verify(&self) {
+        self.server.verify().await;
+    }
+}
+
+fn envelope_for(pagination: PaginationKind) -> Value {
+    match pagination {
+        PaginationKind::None => serde_json::json!({}),
+        PaginationKind::Cursor => serde_json::json!({ "items": [], "nextCursor": null, "hasMore": false }),
+        PaginationKind::Page => serde_json::json!({
+            "items": [],
+            "page": 1,
+            "perPage": 25,
+            "totalPages": 1,
+            "totalItems": 0,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn get_matches_method_path_and_header() {
+        let mock = MockClient::start(|opts| opts.with_api_key("secret")).await;
+
+        mock.expect(
+            ExpectedRequest::new("GET", "/v1/charges/ch_1")
+                .with_header("X-API-Key", "secret")
+                .respond_with(200, serde_json::json!({ "id": "ch_1", "status": "succeeded" })),
+        )
+        .await;
+
+        let response = mock.client().get("/v1/charges/ch_1").await.unwrap();
+        assert_eq!(
+            response,
+            Some(serde_json::json!({ "id": "ch_1", "status": "succeeded" }))
+        );
+
+        mock.verify().await;
+    }
+
+    #[tokio::test]
+    async fn post_matches_idempotency_key_and_body() {
+        let mock = MockClient::start(|opts| opts).await;
+
+        mock.expect(
+            ExpectedRequest::new("POST", "/v1/charges")
+                .with_header("Idempotency-Key", "idem_1")
+                .with_body(serde_json::json!({ "amount": 100 }))
+                .respond_with(201, serde_json::json!({ "id": "ch_1" })),
+        )
+        .await;
+
+        let response = mock
+            .client()
+            .post(
+                "/v1/charges",
+                serde_json::json!({ "amount": 100 }),
+                Some("idem_1".to_string()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response, Some(serde_json::json!({ "id": "ch_1" })));
+
+        mock.verify().await;
+    }
+
+    #[tokio::test]
+    async fn page_paginated_default_envelope_satisfies_paginate_page() {
+        let mock = MockClient::start(|opts| opts).await;
+
+        mock.expect(ExpectedRequest::new("GET", "/v1/items").page_paginated())
+            .await;
+
+        let items: Vec<Value> = mock.client().paginate_page("/v1/items", None).await.unwrap();
+        assert!(items.is_empty());
+
+        mock.verify().await;
+    }
+}